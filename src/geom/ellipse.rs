@@ -4,6 +4,9 @@ use math::num_traits::{Float, NumCast};
 use std;
 use std::ops::Neg;
 
+/// The default resolution used when constructing an `Ellipse` without an explicit one.
+pub const DEFAULT_RESOLUTION: usize = 50;
+
 /// A simple ellipse type with helper methods around the `ellipse` module's functions.
 #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
 pub struct Ellipse<S = f64> {
@@ -11,6 +14,8 @@ pub struct Ellipse<S = f64> {
     pub rect: Rect<S>,
     /// The resolution (number of sides) of the `Ellipse`.
     pub resolution: usize,
+    /// The rotation of the `Ellipse` about its centre in radians.
+    pub rotation: S,
 }
 
 /// A subsection of an `Ellipse`.
@@ -30,21 +35,46 @@ pub struct Section<S = f64> {
 #[allow(missing_copy_implementations)]
 pub struct Circumference<S = f64> {
     index: usize,
+    end: usize,
     num_points: usize,
     middle: Point2<S>,
     rad_step: S,
     rad_offset: S,
     half_w: S,
     half_h: S,
+    /// The cosine of the ellipse rotation, cached so `next` need not recompute it per point.
+    cos_rotation: S,
+    /// The sine of the ellipse rotation.
+    sin_rotation: S,
 }
 
 /// An iterator yielding triangles that describe an oval or some section of an oval.
 #[derive(Clone, Debug)]
 pub struct Triangles<S> {
-    // The last circumference point yielded by the `CircumferenceOffset` iterator.
+    // The last circumference point yielded from the front of the `Circumference` iterator.
     last: Point2<S>,
+    // The last circumference point yielded from the back of the `Circumference` iterator.
+    end_last: Point2<S>,
     // The circumference points used to yield yielded by the `CircumferenceOffset` iterator.
     points: Circumference<S>,
+    // Whether the closing triangle joining `last` and `end_last` has already been emitted.
+    closed: bool,
+}
+
+/// An iterator yielding the triangles of the band (ring) between two `Circumference`s.
+///
+/// The outer and inner circumferences are walked in lockstep, emitting two `Tri`s per step that
+/// together form a quad of the ring. This is suitable for triangulating stroked (outlined) ovals.
+#[derive(Clone, Debug)]
+pub struct TrianglesBetween<S> {
+    // The previous point yielded by the outer `Circumference`.
+    outer_last: Point2<S>,
+    // The previous point yielded by the inner `Circumference`.
+    inner_last: Point2<S>,
+    outer: Circumference<S>,
+    inner: Circumference<S>,
+    // The second triangle of the current quad, buffered until the following call to `next`.
+    buffer: Option<Tri<Point2<S>>>,
 }
 
 impl<S> Ellipse<S>
@@ -53,7 +83,21 @@ where
 {
     /// Construct a new ellipse from its bounding rect and resolution (number of sides).
     pub fn new(rect: Rect<S>, resolution: usize) -> Self {
-        Ellipse { rect, resolution }
+        Ellipse { rect, resolution, rotation: S::zero() }
+    }
+
+    /// Construct an axis-aligned ellipse inscribed within the given bounding rect using the
+    /// `DEFAULT_RESOLUTION`.
+    pub fn from_rect(rect: Rect<S>) -> Self {
+        Self::new(rect, DEFAULT_RESOLUTION)
+    }
+
+    /// Returns the ellipse rotated about its centre by the given angle in radians.
+    ///
+    /// The rotation is applied to every point yielded by `circumference` and `triangles`.
+    pub fn with_rotation(mut self, radians: S) -> Self {
+        self.rotation = radians;
+        self
     }
 
     /// A section of the `Ellipse`.
@@ -70,9 +114,12 @@ where
     }
 
     /// Produces an iterator yielding the points of the ellipse circumference.
-    pub fn circumference(self) -> Circumference<S> {
-        let Ellipse { rect, resolution } = self;
-        Circumference::new(rect, resolution)
+    pub fn circumference(self) -> Circumference<S>
+    where
+        S: Float,
+    {
+        let Ellipse { rect, resolution, rotation } = self;
+        Circumference::new(rect, resolution).rotation(rotation)
     }
 
     /// Produces an iterator yielding the triangles that describe the ellipse.
@@ -86,15 +133,155 @@ where
     }
 }
 
+impl<S> Ellipse<S>
+where
+    S: BaseNum + Float + Neg<Output=S>,
+{
+    /// The smallest circle enclosing all of the given `points`, returned as an `Ellipse`.
+    ///
+    /// This is computed with Welzl's randomized incremental algorithm. The collected points are
+    /// shuffled first, giving expected `O(n)` time regardless of the order in which the caller
+    /// supplies them. The result has a square bounding `Rect` (so it is a true circle) and uses the
+    /// `DEFAULT_RESOLUTION` so it plugs straight into the `circumference`/`triangles` pipeline.
+    pub fn enclosing<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = Point2<S>>,
+    {
+        use rand::seq::SliceRandom;
+        let mut points: Vec<Point2<S>> = points.into_iter().collect();
+        // Randomise the insertion order so adversarial (e.g. pre-sorted) inputs don't degrade the
+        // incremental algorithm to its quadratic worst case.
+        points.shuffle(&mut rand::thread_rng());
+        let (centre, radius) = welzl(&points);
+        let diameter = radius + radius;
+        let rect = Rect::from_x_y_w_h(centre.x, centre.y, diameter, diameter);
+        Self::new(rect, DEFAULT_RESOLUTION)
+    }
+
+    /// The area enclosed by the ellipse, `π · half_w · half_h`.
+    pub fn area(&self) -> S {
+        let (_, _, w, h) = self.rect.x_y_w_h();
+        let two = math::two();
+        let pi = S::from(std::f64::consts::PI).unwrap();
+        pi * (w / two) * (h / two)
+    }
+
+    /// The perimeter of the ellipse.
+    ///
+    /// The exact circumference of an ellipse is non-elementary, so this uses Ramanujan's
+    /// approximation `π · [3(a + b) − √((3a + b)(a + 3b))]` with `a = half_w`, `b = half_h`.
+    pub fn perimeter(&self) -> S {
+        let (_, _, w, h) = self.rect.x_y_w_h();
+        let two = math::two();
+        let three = S::from(3.0).unwrap();
+        let pi = S::from(std::f64::consts::PI).unwrap();
+        let a = w / two;
+        let b = h / two;
+        pi * (three * (a + b) - ((three * a + b) * (a + three * b)).sqrt())
+    }
+
+    /// Whether the given point lies within (or on the boundary of) the ellipse.
+    ///
+    /// The test honours the ellipse rotation by first mapping the point into the ellipse's local
+    /// frame.
+    pub fn contains(&self, point: Point2<S>) -> bool {
+        let (lx, ly, half_w, half_h) = self.to_local(point);
+        let nx = lx / half_w;
+        let ny = ly / half_h;
+        nx * nx + ny * ny <= S::one()
+    }
+
+    /// The point on the ellipse boundary closest to the given point.
+    ///
+    /// The projection is computed in the ellipse's local frame by iteratively refining the
+    /// parametric angle (each step moves along the local circle of curvature, centred on the
+    /// evolute), then mapped back into world space, so it honours the ellipse rotation.
+    pub fn closest_point(&self, point: Point2<S>) -> Point2<S> {
+        let (lx, ly, half_w, half_h) = self.to_local(point);
+        let two = math::two();
+        let pi = S::from(std::f64::consts::PI).unwrap();
+        let px = lx.abs();
+        let py = ly.abs();
+        let a = half_w;
+        let b = half_h;
+        let mut t = pi / S::from(4.0).unwrap();
+        for _ in 0..4 {
+            let x = a * t.cos();
+            let y = b * t.sin();
+            let ex = (a * a - b * b) * t.cos().powi(3) / a;
+            let ey = (b * b - a * a) * t.sin().powi(3) / b;
+            let rx = x - ex;
+            let ry = y - ey;
+            let qx = px - ex;
+            let qy = py - ey;
+            let r = (rx * rx + ry * ry).sqrt();
+            let q = (qx * qx + qy * qy).sqrt();
+            // `r * q` vanishes when the query point maps onto the ellipse's evolute (reachable for
+            // interior points); skip the update in that degenerate case to avoid propagating `NaN`.
+            let rq = r * q;
+            if rq <= S::zero() {
+                break;
+            }
+            let delta_c = r * ((rx * qy - ry * qx) / rq).asin();
+            let delta_t = delta_c / (a * a + b * b - x * x - y * y).sqrt();
+            t = t + delta_t;
+            t = t.max(S::zero()).min(pi / two);
+        }
+        // Restore the original quadrant before mapping back into world space.
+        let local: Point2<S> = [a * t.cos() * lx.signum(), b * t.sin() * ly.signum()].into();
+        self.to_world(local)
+    }
+
+    /// Produces an iterator yielding the triangles of the stroked (outlined) oval, i.e. the band
+    /// between this ellipse and one scaled inward by `thickness` on each axis.
+    ///
+    /// The outline honours the ellipse rotation and resolution. To outline a partial arc, call
+    /// `outline_triangles` on a `Section` instead.
+    pub fn outline_triangles(self, thickness: S) -> TrianglesBetween<S> {
+        let two = math::two();
+        let (cx, cy, w, h) = self.rect.x_y_w_h();
+        let inner_rect = Rect::from_x_y_w_h(cx, cy, w - two * thickness, h - two * thickness);
+        let inner = Ellipse::new(inner_rect, self.resolution)
+            .with_rotation(self.rotation)
+            .circumference();
+        self.circumference().tris_between(inner)
+    }
+
+    // Map a world-space point into the ellipse's local (axis-aligned, centred) frame, also
+    // returning the half-width and half-height for convenience.
+    fn to_local(&self, point: Point2<S>) -> (S, S, S, S) {
+        let (cx, cy, w, h) = self.rect.x_y_w_h();
+        let two = math::two();
+        let dx = point.x - cx;
+        let dy = point.y - cy;
+        let (cos_r, sin_r) = (self.rotation.cos(), self.rotation.sin());
+        let lx = dx * cos_r + dy * sin_r;
+        let ly = -dx * sin_r + dy * cos_r;
+        (lx, ly, w / two, h / two)
+    }
+
+    // Map a point from the ellipse's local frame back into world space.
+    fn to_world(&self, local: Point2<S>) -> Point2<S> {
+        let (cx, cy, _, _) = self.rect.x_y_w_h();
+        let (cos_r, sin_r) = (self.rotation.cos(), self.rotation.sin());
+        let x = cx + local.x * cos_r - local.y * sin_r;
+        let y = cy + local.x * sin_r + local.y * cos_r;
+        [x, y].into()
+    }
+}
+
 impl<S> Section<S>
 where
     S: BaseNum + Neg<Output=S>,
 {
     /// Produces an iterator yielding the points of the ellipse circumference.
-    pub fn circumference(self) -> Circumference<S> {
+    pub fn circumference(self) -> Circumference<S>
+    where
+        S: Float,
+    {
         let Section { ellipse, offset_radians, section_radians } = self;
         let circ = Circumference::new_section(ellipse.rect, ellipse.resolution, section_radians);
-        circ.offset_radians(offset_radians)
+        circ.rotation(ellipse.rotation).offset_radians(offset_radians)
     }
 
     /// Produces an iterator yielding the triangles that describe the ellipse section.
@@ -106,6 +293,26 @@ where
     {
         self.circumference().triangles()
     }
+
+    /// Produces an iterator yielding the triangles of the stroked (outlined) arc, i.e. the band
+    /// between this section and one scaled inward by `thickness` on each axis.
+    ///
+    /// Like `Ellipse::outline_triangles` but restricted to the section's arc, so it honours the
+    /// `offset_radians`/`section_radians` of the partial arc as well as the ellipse rotation.
+    pub fn outline_triangles(self, thickness: S) -> TrianglesBetween<S>
+    where
+        S: Float,
+    {
+        let Section { ellipse, offset_radians, section_radians } = self;
+        let two = math::two();
+        let (cx, cy, w, h) = ellipse.rect.x_y_w_h();
+        let inner_rect = Rect::from_x_y_w_h(cx, cy, w - two * thickness, h - two * thickness);
+        let inner = Ellipse::new(inner_rect, ellipse.resolution)
+            .with_rotation(ellipse.rotation)
+            .section(offset_radians, section_radians)
+            .circumference();
+        self.circumference().tris_between(inner)
+    }
 }
 
 impl<S> Circumference<S>
@@ -117,12 +324,15 @@ where
         let two = math::two();
         Circumference {
             index: 0,
+            end: num_points,
             num_points: num_points,
             middle: [x, y].into(),
             half_w: w / two,
             half_h: h / two,
             rad_step: rad_step,
             rad_offset: S::zero(),
+            cos_rotation: S::one(),
+            sin_rotation: S::zero(),
         }
     }
 
@@ -160,6 +370,19 @@ where
         self
     }
 
+    /// Rotates the whole circumference about the ellipse's centre by the given angle in radians.
+    ///
+    /// Each emitted point is first computed on the axis-aligned ellipse and then rotated by
+    /// `radians`, so both the `circumference` and `triangles` paths honour the orientation.
+    pub fn rotation(mut self, radians: S) -> Self
+    where
+        S: Float,
+    {
+        self.cos_rotation = radians.cos();
+        self.sin_rotation = radians.sin();
+        self
+    }
+
     /// Rotates the position at which the iterator starts yielding points by the given radians.
     ///
     /// This is particularly useful for yielding a different section of the circumference when
@@ -178,7 +401,43 @@ where
         S: Float,
     {
         let last = self.next().unwrap_or(self.middle);
-        Triangles { last, points: self }
+        let end_last = self.next_back().unwrap_or(last);
+        Triangles { last, end_last, points: self, closed: false }
+    }
+
+    /// Produces an iterator yielding the triangles of the ring between this (outer) circumference
+    /// and the given `inner` circumference.
+    ///
+    /// The two iterators are walked in lockstep, so they should share the same `rad_step`,
+    /// `rad_offset` and number of points (as produced by `Ellipse::outline_triangles`).
+    pub fn tris_between(mut self, mut inner: Circumference<S>) -> TrianglesBetween<S>
+    where
+        S: Float,
+    {
+        let outer_last = self.next().unwrap_or(self.middle);
+        let inner_last = inner.next().unwrap_or(inner.middle);
+        TrianglesBetween {
+            outer_last,
+            inner_last,
+            outer: self,
+            inner,
+            buffer: None,
+        }
+    }
+}
+
+impl<S> Circumference<S>
+where
+    S: BaseNum + Float,
+{
+    /// The circumference point at the given step index, with the ellipse rotation applied.
+    fn point_at(&self, index: usize) -> Point2<S> {
+        let index_s: S = NumCast::from(index).unwrap();
+        let dx = self.half_w * (self.rad_offset + self.rad_step * index_s).cos();
+        let dy = self.half_h * (self.rad_offset + self.rad_step * index_s).sin();
+        let x = self.middle.x + dx * self.cos_rotation - dy * self.sin_rotation;
+        let y = self.middle.y + dx * self.sin_rotation + dy * self.cos_rotation;
+        [x, y].into()
     }
 }
 
@@ -188,23 +447,12 @@ where
 {
     type Item = Point2<S>;
     fn next(&mut self) -> Option<Self::Item> {
-        let Circumference {
-            ref mut index,
-            num_points,
-            middle,
-            rad_step,
-            rad_offset,
-            half_w,
-            half_h,
-        } = *self;
-        if *index >= num_points {
+        if self.index >= self.end {
             return None;
         }
-        let index_s: S = NumCast::from(*index).unwrap();
-        let x = middle.x + half_w * (rad_offset + rad_step * index_s).cos();
-        let y = middle.y + half_h * (rad_offset + rad_step * index_s).sin();
-        *index += 1;
-        Some([x, y].into())
+        let point = self.point_at(self.index);
+        self.index += 1;
+        Some(point)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -213,19 +461,25 @@ where
     }
 }
 
-// TODO:
-// impl<S> DoubleEndedIterator for Circumference<S>
-// where
-//     S: BaseNum + Float,
-// {
-// }
+impl<S> DoubleEndedIterator for Circumference<S>
+where
+    S: BaseNum + Float,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(self.point_at(self.end))
+    }
+}
 
 impl<S> ExactSizeIterator for Circumference<S>
 where
     S: BaseNum + Float,
 {
     fn len(&self) -> usize {
-        self.num_points - self.index
+        self.end - self.index
     }
 }
 
@@ -235,12 +489,17 @@ where
 {
     type Item = Tri<Point2<S>>;
     fn next(&mut self) -> Option<Self::Item> {
-        let Triangles { ref mut points, ref mut last } = *self;
-        points.next().map(|next| {
+        let Triangles { ref mut points, ref mut last, end_last, ref mut closed } = *self;
+        if let Some(next) = points.next() {
             let triangle = Tri([points.middle, *last, next]);
             *last = next;
-            triangle
-        })
+            Some(triangle)
+        } else if !*closed {
+            *closed = true;
+            Some(Tri([points.middle, *last, end_last]))
+        } else {
+            None
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -249,11 +508,290 @@ where
     }
 }
 
+impl<S> DoubleEndedIterator for Triangles<S>
+where
+    S: BaseNum + Float,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let Triangles { ref mut points, last, ref mut end_last, ref mut closed } = *self;
+        if let Some(prev) = points.next_back() {
+            let triangle = Tri([points.middle, prev, *end_last]);
+            *end_last = prev;
+            Some(triangle)
+        } else if !*closed {
+            *closed = true;
+            Some(Tri([points.middle, last, *end_last]))
+        } else {
+            None
+        }
+    }
+}
+
 impl<S> ExactSizeIterator for Triangles<S>
 where
     S: BaseNum + Float,
 {
     fn len(&self) -> usize {
-        self.points.len()
+        self.points.len() + if self.closed { 0 } else { 1 }
+    }
+}
+
+impl<S> Iterator for TrianglesBetween<S>
+where
+    S: BaseNum + Float,
+{
+    type Item = Tri<Point2<S>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(tri) = self.buffer.take() {
+            return Some(tri);
+        }
+        let outer = self.outer.next()?;
+        let inner = self.inner.next()?;
+        // Split the quad [outer_last, inner_last, inner, outer] into two triangles.
+        let tri_a = Tri([self.outer_last, self.inner_last, outer]);
+        let tri_b = Tri([self.inner_last, inner, outer]);
+        self.outer_last = outer;
+        self.inner_last = inner;
+        self.buffer = Some(tri_b);
+        Some(tri_a)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<S> ExactSizeIterator for TrianglesBetween<S>
+where
+    S: BaseNum + Float,
+{
+    fn len(&self) -> usize {
+        let steps = std::cmp::min(self.outer.len(), self.inner.len());
+        steps * 2 + if self.buffer.is_some() { 1 } else { 0 }
+    }
+}
+
+// The squared distance between two points.
+fn distance_sq<S>(a: Point2<S>, b: Point2<S>) -> S
+where
+    S: BaseNum + Float,
+{
+    let dx = a.x - b.x;
+    let dy = a.y - b.y;
+    dx * dx + dy * dy
+}
+
+// Whether the point lies within (or on the boundary of) the `(centre, radius)` circle.
+//
+// A small tolerance is allowed so that points used to build the circle are reliably counted as
+// enclosed in the face of floating point error.
+fn circle_contains<S>(circle: (Point2<S>, S), p: Point2<S>) -> bool
+where
+    S: BaseNum + Float,
+{
+    let (centre, radius) = circle;
+    let r2 = radius * radius;
+    let eps = S::from(1e-7).unwrap();
+    distance_sq(centre, p) <= r2 + eps * (r2 + S::one())
+}
+
+// The circle whose diameter is the segment between `a` and `b`.
+fn diameter_circle<S>(a: Point2<S>, b: Point2<S>) -> (Point2<S>, S)
+where
+    S: BaseNum + Float,
+{
+    let two = math::two();
+    let centre: Point2<S> = [(a.x + b.x) / two, (a.y + b.y) / two].into();
+    (centre, distance_sq(a, b).sqrt() / two)
+}
+
+// The circle passing through all three points (their circumscribed circle).
+fn circumscribed_circle<S>(a: Point2<S>, b: Point2<S>, c: Point2<S>) -> (Point2<S>, S)
+where
+    S: BaseNum + Float,
+{
+    let two = math::two();
+    // Work relative to `a` so the linear system simplifies, then offset the result back.
+    let (bx, by) = (b.x - a.x, b.y - a.y);
+    let (cx, cy) = (c.x - a.x, c.y - a.y);
+    let bb = bx * bx + by * by;
+    let cc = cx * cx + cy * cy;
+    let d = two * (bx * cy - by * cx);
+    // Collinear points have no circumscribed circle (`d` vanishes). Fall back to the diameter
+    // circle of the two farthest of the three, which still encloses the third.
+    if d.abs() <= S::from(1e-12).unwrap() {
+        return diameter_circle_of_farthest(a, b, c);
+    }
+    let centre: Point2<S> = [
+        a.x + (cy * bb - by * cc) / d,
+        a.y + (bx * cc - cx * bb) / d,
+    ].into();
+    (centre, distance_sq(centre, a).sqrt())
+}
+
+// The diameter circle spanning the two farthest apart of three (collinear) points.
+fn diameter_circle_of_farthest<S>(a: Point2<S>, b: Point2<S>, c: Point2<S>) -> (Point2<S>, S)
+where
+    S: BaseNum + Float,
+{
+    let ab = distance_sq(a, b);
+    let ac = distance_sq(a, c);
+    let bc = distance_sq(b, c);
+    if ab >= ac && ab >= bc {
+        diameter_circle(a, b)
+    } else if ac >= bc {
+        diameter_circle(a, c)
+    } else {
+        diameter_circle(b, c)
+    }
+}
+
+// The smallest circle enclosing all `points`, grown so that `a` lies on its boundary.
+fn welzl_with_one<S>(points: &[Point2<S>], a: Point2<S>) -> (Point2<S>, S)
+where
+    S: BaseNum + Float,
+{
+    let mut circle = (a, S::zero());
+    for (j, &q) in points.iter().enumerate() {
+        if !circle_contains(circle, q) {
+            circle = welzl_with_two(&points[..j], a, q);
+        }
+    }
+    circle
+}
+
+// The smallest circle enclosing all `points`, grown so that both `a` and `b` lie on its boundary.
+fn welzl_with_two<S>(points: &[Point2<S>], a: Point2<S>, b: Point2<S>) -> (Point2<S>, S)
+where
+    S: BaseNum + Float,
+{
+    let mut circle = diameter_circle(a, b);
+    for &q in points {
+        if !circle_contains(circle, q) {
+            circle = circumscribed_circle(a, b, q);
+        }
+    }
+    circle
+}
+
+// Welzl's incremental minimum enclosing circle over the given points, returned as `(centre,
+// radius)`. The points are visited in their given order, so `enclosing` shuffles them beforehand
+// to obtain the algorithm's expected `O(n)` running time.
+fn welzl<S>(points: &[Point2<S>]) -> (Point2<S>, S)
+where
+    S: BaseNum + Float,
+{
+    let mut circle: (Point2<S>, S) = ([S::zero(), S::zero()].into(), S::zero());
+    for (i, &p) in points.iter().enumerate() {
+        if !circle_contains(circle, p) {
+            circle = welzl_with_one(&points[..i], p);
+        }
+    }
+    circle
+}
+
+#[cfg(test)]
+mod tests {
+    use geom::Rect;
+    use math::Point2;
+    use super::{DEFAULT_RESOLUTION, Ellipse};
+
+    const EPS: f64 = 1e-6;
+
+    fn pt(x: f64, y: f64) -> Point2<f64> {
+        [x, y].into()
+    }
+
+    #[test]
+    fn circumference_rev_matches_reversed_forward() {
+        let ellipse = Ellipse::new(Rect::from_x_y_w_h(0.0, 0.0, 4.0, 2.0), 8);
+        let mut forward: Vec<_> = ellipse.circumference().collect();
+        let backward: Vec<_> = ellipse.circumference().rev().collect();
+        forward.reverse();
+        assert_eq!(forward.len(), backward.len());
+        for (a, b) in forward.iter().zip(&backward) {
+            assert!((a.x - b.x).abs() < EPS && (a.y - b.y).abs() < EPS);
+        }
+    }
+
+    #[test]
+    fn triangles_exact_size() {
+        let ellipse = Ellipse::new(Rect::from_x_y_w_h(0.0, 0.0, 4.0, 2.0), 8);
+        let tris = ellipse.triangles();
+        let len = tris.len();
+        assert_eq!(len, tris.count());
+    }
+
+    #[test]
+    fn enclosing_unit_square() {
+        let points = vec![pt(-1.0, -1.0), pt(1.0, -1.0), pt(1.0, 1.0), pt(-1.0, 1.0)];
+        let ellipse = Ellipse::enclosing(points);
+        let (x, y, w, h) = ellipse.rect.x_y_w_h();
+        assert!(x.abs() < EPS && y.abs() < EPS);
+        // The circle through the corners has radius sqrt(2), so width == height == 2·sqrt(2).
+        let expected = 2.0 * 2.0f64.sqrt();
+        assert!((w - expected).abs() < EPS && (h - expected).abs() < EPS);
+        assert_eq!(ellipse.resolution, DEFAULT_RESOLUTION);
+    }
+
+    #[test]
+    fn enclosing_collinear_points() {
+        // Collinear inputs have no circumscribed circle; the result must stay finite and enclose
+        // every point (the segment [(0,0), (3,0)] has centre (1.5, 0) and radius 1.5).
+        let points = vec![pt(0.0, 0.0), pt(3.0, 0.0), pt(1.0, 0.0)];
+        let ellipse = Ellipse::enclosing(points);
+        let (x, y, w, h) = ellipse.rect.x_y_w_h();
+        assert!(x.is_finite() && y.is_finite() && w.is_finite() && h.is_finite());
+        assert!((x - 1.5).abs() < EPS && y.abs() < EPS);
+        assert!((w - 3.0).abs() < EPS && (h - 3.0).abs() < EPS);
+    }
+
+    #[test]
+    fn analytic_queries() {
+        use std::f64::consts::PI;
+        // Half-width 2, half-height 1.
+        let ellipse = Ellipse::from_rect(Rect::from_x_y_w_h(0.0, 0.0, 4.0, 2.0));
+        assert!((ellipse.area() - PI * 2.0 * 1.0).abs() < EPS);
+        let (a, b) = (2.0f64, 1.0f64);
+        let expected = PI * (3.0 * (a + b) - ((3.0 * a + b) * (a + 3.0 * b)).sqrt());
+        assert!((ellipse.perimeter() - expected).abs() < EPS);
+        assert!(ellipse.contains(pt(0.0, 0.0)));
+        assert!(ellipse.contains(pt(2.0, 0.0)));
+        assert!(!ellipse.contains(pt(2.1, 0.0)));
+    }
+
+    #[test]
+    fn closest_point_on_circle() {
+        // Unit circle centred at the origin: the nearest boundary point to (5, 0) is (1, 0).
+        let circle = Ellipse::from_rect(Rect::from_x_y_w_h(0.0, 0.0, 2.0, 2.0));
+        let closest = circle.closest_point(pt(5.0, 0.0));
+        assert!((closest.x - 1.0).abs() < 1e-4 && closest.y.abs() < 1e-4);
+    }
+
+    #[test]
+    fn closest_point_interior_is_finite() {
+        // An interior point can map onto the evolute; the result must not be `NaN`.
+        let ellipse = Ellipse::from_rect(Rect::from_x_y_w_h(0.0, 0.0, 4.0, 2.0));
+        let closest = ellipse.closest_point(pt(0.0, 0.0));
+        assert!(closest.x.is_finite() && closest.y.is_finite());
+    }
+
+    #[test]
+    fn outline_triangles_exact_size() {
+        let ellipse = Ellipse::from_rect(Rect::from_x_y_w_h(0.0, 0.0, 4.0, 4.0));
+        let tris = ellipse.outline_triangles(0.5);
+        let len = tris.len();
+        assert_eq!(len, tris.count());
+    }
+
+    #[test]
+    fn section_outline_triangles_exact_size() {
+        use std::f64::consts::PI;
+        let section = Ellipse::from_rect(Rect::from_x_y_w_h(0.0, 0.0, 4.0, 4.0))
+            .section(0.0, PI);
+        let tris = section.outline_triangles(0.5);
+        let len = tris.len();
+        assert_eq!(len, tris.count());
     }
 }